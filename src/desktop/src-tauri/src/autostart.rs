@@ -0,0 +1,78 @@
+// ============ OS startup integration ============
+//
+// `NodeSettings` already carries `auto_start`/`start_minimized`, but
+// nothing wired them into the OS. This wires `auto_start` into the
+// platform's real login-item mechanism (Windows Run key / macOS
+// LaunchAgent / XDG autostart) via the `auto-launch` crate, and gives the
+// frontend a command to re-sync both settings without restarting the app.
+
+use crate::NodeSettings;
+use auto_launch::AutoLaunchBuilder;
+use tauri::{AppHandle, Manager};
+
+const APP_NAME: &str = "RhizOS";
+
+fn build_auto_launch() -> Result<auto_launch::AutoLaunch, String> {
+    let exe_path = std::env::current_exe()
+        .map_err(|e| format!("Failed to determine executable path: {}", e))?;
+    let exe_path = exe_path
+        .to_str()
+        .ok_or("Executable path is not valid UTF-8")?;
+
+    AutoLaunchBuilder::new()
+        .set_app_name(APP_NAME)
+        .set_app_path(exe_path)
+        .set_args(&[])
+        .build()
+        .map_err(|e| format!("Failed to configure login item: {}", e))
+}
+
+/// Registers or removes the OS login entry, and hides the main window if
+/// the node should start minimized to the tray. The window show/hide only
+/// makes sense at actual app startup (or when the frontend explicitly asks
+/// to re-sync via `apply_startup_settings`) — callers that just persist an
+/// edited setting should use `apply_auto_start` instead, or they'll yank a
+/// visible window into the tray on every save.
+pub fn sync_startup_settings(app: &AppHandle, settings: &NodeSettings) -> Result<(), String> {
+    apply_auto_start(settings.auto_start)?;
+
+    if let Some(window) = app.get_webview_window("main") {
+        if settings.start_minimized {
+            window
+                .hide()
+                .map_err(|e| format!("Failed to hide main window: {}", e))?;
+        } else {
+            window
+                .show()
+                .map_err(|e| format!("Failed to show main window: {}", e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Registers or removes the OS login item for `auto_start`, without
+/// touching window visibility. Safe to call on every settings save.
+pub(crate) fn apply_auto_start(enabled: bool) -> Result<(), String> {
+    let auto_launch = build_auto_launch()?;
+    let is_enabled = auto_launch
+        .is_enabled()
+        .map_err(|e| format!("Failed to read login item state: {}", e))?;
+
+    if enabled && !is_enabled {
+        auto_launch
+            .enable()
+            .map_err(|e| format!("Failed to register login item: {}", e))?;
+    } else if !enabled && is_enabled {
+        auto_launch
+            .disable()
+            .map_err(|e| format!("Failed to remove login item: {}", e))?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn apply_startup_settings(app: AppHandle, settings: NodeSettings) -> Result<(), String> {
+    sync_startup_settings(&app, &settings)
+}
@@ -0,0 +1,106 @@
+// ============ Earnings estimation ============
+//
+// `PricingConfig` defines GPU/CPU/memory hourly rates and a minimum, but
+// nothing computed what a node would actually earn. This estimates
+// projected revenue from the detected hardware and a set of projected
+// utilization levels, so operators have a concrete reason to keep their
+// node online and can tune pricing before committing.
+
+use crate::{hardware, load_settings, NodeSettings};
+use serde::{Deserialize, Serialize};
+
+const HOURS_PER_DAY: f64 = 24.0;
+const DAYS_PER_MONTH: f64 = 30.0;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EarningsRequest {
+    /// Utilization levels to evaluate, each in 0.0..=100.0. A single
+    /// value gives a point estimate; several let the UI plot a curve.
+    pub utilization_percents: Vec<f32>,
+    /// Expected fraction of time the node is online, in 0.0..=100.0.
+    pub uptime_percent: f32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct EarningsBreakdown {
+    pub gpu_cents: u64,
+    pub cpu_cents: u64,
+    pub memory_cents: u64,
+    pub total_cents: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EarningsEstimate {
+    pub utilization_percent: f32,
+    pub hourly: EarningsBreakdown,
+    pub daily: EarningsBreakdown,
+    pub monthly: EarningsBreakdown,
+}
+
+#[tauri::command]
+pub async fn estimate_earnings(request: EarningsRequest) -> Result<Vec<EarningsEstimate>, String> {
+    let hw = tauri::async_runtime::spawn_blocking(hardware::detect_hardware)
+        .await
+        .map_err(|e| format!("Failed to detect hardware: {}", e))??;
+    let settings = load_settings().unwrap_or_default();
+    let uptime_fraction = (request.uptime_percent as f64 / 100.0).clamp(0.0, 1.0);
+
+    Ok(request
+        .utilization_percents
+        .iter()
+        .map(|&utilization_percent| {
+            let hourly = hourly_breakdown(&hw, &settings, utilization_percent);
+            EarningsEstimate {
+                utilization_percent,
+                daily: scale_breakdown(hourly, HOURS_PER_DAY * uptime_fraction),
+                monthly: scale_breakdown(hourly, HOURS_PER_DAY * DAYS_PER_MONTH * uptime_fraction),
+                hourly,
+            }
+        })
+        .collect())
+}
+
+/// Spreads the node's resources evenly across its job slots so the
+/// `minimum_cents` floor can be applied per slot before summing, then
+/// scales by how many slots are expected to be active at this
+/// utilization level.
+fn hourly_breakdown(
+    hw: &hardware::HardwareInfo,
+    settings: &NodeSettings,
+    utilization_percent: f32,
+) -> EarningsBreakdown {
+    let slots = settings.max_concurrent_jobs.max(1) as f64;
+    let utilization_fraction = (utilization_percent as f64 / 100.0).clamp(0.0, 1.0);
+    let active_slots = (utilization_fraction * slots).round();
+
+    let memory_gb = hw.memory.available_mb as f64 / 1024.0;
+    let per_slot_gpu = hw.gpus.len() as f64 / slots * settings.pricing.gpu_hour_cents as f64;
+    let per_slot_cpu = hw.cpu.cores as f64 / slots * settings.pricing.cpu_core_hour_cents as f64;
+    let per_slot_memory = memory_gb / slots * settings.pricing.memory_gb_hour_cents as f64;
+    let per_slot_total = (per_slot_gpu + per_slot_cpu + per_slot_memory)
+        .max(settings.pricing.minimum_cents as f64);
+
+    // Scale each resource's share of the per-slot rate by the same floor
+    // adjustment so the parts still sum to the floored total.
+    let floor_ratio = if per_slot_gpu + per_slot_cpu + per_slot_memory > 0.0 {
+        per_slot_total / (per_slot_gpu + per_slot_cpu + per_slot_memory)
+    } else {
+        1.0
+    };
+
+    EarningsBreakdown {
+        gpu_cents: (per_slot_gpu * floor_ratio * active_slots).round() as u64,
+        cpu_cents: (per_slot_cpu * floor_ratio * active_slots).round() as u64,
+        memory_cents: (per_slot_memory * floor_ratio * active_slots).round() as u64,
+        total_cents: (per_slot_total * active_slots).round() as u64,
+    }
+}
+
+fn scale_breakdown(hourly: EarningsBreakdown, hours: f64) -> EarningsBreakdown {
+    EarningsBreakdown {
+        gpu_cents: (hourly.gpu_cents as f64 * hours).round() as u64,
+        cpu_cents: (hourly.cpu_cents as f64 * hours).round() as u64,
+        memory_cents: (hourly.memory_cents as f64 * hours).round() as u64,
+        total_cents: (hourly.total_cents as f64 * hours).round() as u64,
+    }
+}
@@ -0,0 +1,368 @@
+// ============ Hardware detection + live telemetry ============
+//
+// Detects the host's real CPU/memory/disk/GPU specs (instead of the
+// placeholder zeros the UI used to ship with) and runs a background loop
+// that samples temperatures/utilization so operators can see thermal
+// headroom while a job is running.
+
+use serde::Serialize;
+use std::process::Command;
+use std::time::Duration;
+use sysinfo::System;
+use tauri::{AppHandle, Emitter};
+
+const TELEMETRY_EVENT: &str = "hardware-telemetry";
+const TELEMETRY_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HardwareInfo {
+    pub cpu: CpuInfo,
+    pub memory: MemoryInfo,
+    pub gpus: Vec<GpuInfo>,
+    pub storage: StorageInfo,
+    pub docker_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CpuInfo {
+    pub model: String,
+    pub cores: u32,
+    pub threads: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryInfo {
+    pub total_mb: u64,
+    pub available_mb: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GpuInfo {
+    pub model: String,
+    pub vram_mb: u64,
+    pub driver_version: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StorageInfo {
+    pub total_gb: u64,
+    pub available_gb: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HardwareTelemetry {
+    pub cpu_temp_c: Option<f32>,
+    pub cpu_util_percent: f32,
+    pub gpus: Vec<GpuTelemetry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GpuTelemetry {
+    pub index: u32,
+    pub temp_c: Option<f32>,
+    pub utilization_percent: Option<f32>,
+}
+
+#[tauri::command]
+pub async fn get_hardware_info() -> Result<HardwareInfo, String> {
+    tauri::async_runtime::spawn_blocking(detect_hardware)
+        .await
+        .map_err(|e| format!("Failed to detect hardware: {}", e))?
+}
+
+pub(crate) fn detect_hardware() -> Result<HardwareInfo, String> {
+    let mut sys = System::new_all();
+    sys.refresh_all();
+
+    let cpu_model = sys
+        .cpus()
+        .first()
+        .map(|cpu| cpu.brand().trim().to_string())
+        .filter(|model| !model.is_empty())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let cpu = CpuInfo {
+        model: cpu_model,
+        cores: num_cpus::get_physical() as u32,
+        threads: num_cpus::get() as u32,
+    };
+
+    let memory = MemoryInfo {
+        total_mb: sys.total_memory() / 1024 / 1024,
+        available_mb: sys.available_memory() / 1024 / 1024,
+    };
+
+    let storage = detect_storage();
+    let gpus = detect_gpus();
+    let docker_version = detect_docker_version();
+
+    Ok(HardwareInfo {
+        cpu,
+        memory,
+        gpus,
+        storage,
+        docker_version,
+    })
+}
+
+fn detect_storage() -> StorageInfo {
+    use sysinfo::Disks;
+
+    let disks = Disks::new_with_refreshed_list();
+    let (total, available) = disks
+        .list()
+        .iter()
+        .fold((0u64, 0u64), |(total, available), disk| {
+            (
+                total + disk.total_space(),
+                available + disk.available_space(),
+            )
+        });
+
+    StorageInfo {
+        total_gb: total / 1024 / 1024 / 1024,
+        available_gb: available / 1024 / 1024 / 1024,
+    }
+}
+
+fn detect_docker_version() -> Option<String> {
+    let output = Command::new("docker")
+        .args(["version", "--format", "{{.Server.Version}}"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if version.is_empty() {
+        None
+    } else {
+        Some(version)
+    }
+}
+
+// ---- GPU detection ----
+//
+// NVIDIA hardware is queried through NVML first since it gives us
+// structured fields without scraping CLI output. When NVML isn't
+// available (no driver, or a non-NVIDIA box) we fall back to parsing
+// `nvidia-smi`/`rocm-smi`, which is slower but works anywhere the vendor
+// tools are installed.
+
+fn detect_gpus() -> Vec<GpuInfo> {
+    if let Some(gpus) = detect_gpus_nvml() {
+        if !gpus.is_empty() {
+            return gpus;
+        }
+    }
+
+    let mut gpus = detect_gpus_nvidia_smi();
+    gpus.extend(detect_gpus_rocm_smi());
+    gpus
+}
+
+fn detect_gpus_nvml() -> Option<Vec<GpuInfo>> {
+    use nvml_wrapper::Nvml;
+
+    let nvml = Nvml::init().ok()?;
+    let count = nvml.device_count().ok()?;
+
+    let mut gpus = Vec::with_capacity(count as usize);
+    for index in 0..count {
+        let device = nvml.device_by_index(index).ok()?;
+        let model = device.name().unwrap_or_else(|_| "Unknown NVIDIA GPU".to_string());
+        let vram_mb = device
+            .memory_info()
+            .map(|mem| mem.total / 1024 / 1024)
+            .unwrap_or(0);
+        let driver_version = nvml.sys_driver_version().unwrap_or_default();
+
+        gpus.push(GpuInfo {
+            model,
+            vram_mb,
+            driver_version,
+        });
+    }
+
+    Some(gpus)
+}
+
+fn detect_gpus_nvidia_smi() -> Vec<GpuInfo> {
+    let output = match Command::new("nvidia-smi")
+        .args([
+            "--query-gpu=name,memory.total,driver_version",
+            "--format=csv,noheader,nounits",
+        ])
+        .output()
+    {
+        Ok(output) if output.status.success() => output,
+        _ => return Vec::new(),
+    };
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+            let [model, vram, driver] = fields[..] else {
+                return None;
+            };
+
+            Some(GpuInfo {
+                model: model.to_string(),
+                vram_mb: vram.parse().unwrap_or(0),
+                driver_version: driver.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn detect_gpus_rocm_smi() -> Vec<GpuInfo> {
+    let output = match Command::new("rocm-smi")
+        .args(["--showproductname", "--showmeminfo", "vram", "--showdriverversion", "--csv"])
+        .output()
+    {
+        Ok(output) if output.status.success() => output,
+        _ => return Vec::new(),
+    };
+
+    // rocm-smi's --csv output is one row per GPU with a header row; we
+    // only need enough of it to populate the fields the UI shows.
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(',').collect();
+            if fields.len() < 3 {
+                return None;
+            }
+
+            Some(GpuInfo {
+                model: fields[1].trim().to_string(),
+                vram_mb: fields
+                    .get(2)
+                    .and_then(|v| v.trim().parse::<u64>().ok())
+                    .unwrap_or(0)
+                    / 1024
+                    / 1024,
+                driver_version: fields
+                    .get(3)
+                    .map(|v| v.trim().to_string())
+                    .unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+// ---- Telemetry loop ----
+
+/// Spawns a background task that samples CPU/GPU temperature and
+/// utilization on an interval and emits them to the frontend. Intended to
+/// be called once from `main`'s `setup` hook.
+pub fn spawn_telemetry_loop(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut sys = System::new_all();
+
+        loop {
+            tokio::time::sleep(TELEMETRY_INTERVAL).await;
+
+            let telemetry = tauri::async_runtime::spawn_blocking(move || {
+                sys.refresh_cpu_usage();
+                let cpu_util_percent = sys.global_cpu_usage();
+                let cpu_temp_c = read_cpu_temp();
+                let gpus = read_gpu_telemetry();
+                (sys, cpu_util_percent, cpu_temp_c, gpus)
+            })
+            .await;
+
+            let Ok((refreshed_sys, cpu_util_percent, cpu_temp_c, gpus)) = telemetry else {
+                continue;
+            };
+            sys = refreshed_sys;
+
+            let payload = HardwareTelemetry {
+                cpu_temp_c,
+                cpu_util_percent,
+                gpus,
+            };
+
+            if app.emit(TELEMETRY_EVENT, &payload).is_err() {
+                // No windows/listeners left (e.g. during shutdown); keep
+                // sampling in case one reattaches.
+            }
+        }
+    });
+}
+
+fn read_cpu_temp() -> Option<f32> {
+    use sysinfo::Components;
+
+    let components = Components::new_with_refreshed_list();
+    components
+        .list()
+        .iter()
+        .find(|c| {
+            let label = c.label().to_lowercase();
+            label.contains("cpu") || label.contains("package") || label.contains("tctl")
+        })
+        .and_then(|c| c.temperature())
+}
+
+fn read_gpu_telemetry() -> Vec<GpuTelemetry> {
+    if let Some(gpus) = read_gpu_telemetry_nvml() {
+        return gpus;
+    }
+    read_gpu_telemetry_nvidia_smi()
+}
+
+fn read_gpu_telemetry_nvml() -> Option<Vec<GpuTelemetry>> {
+    use nvml_wrapper::Nvml;
+
+    let nvml = Nvml::init().ok()?;
+    let count = nvml.device_count().ok()?;
+
+    let mut gpus = Vec::with_capacity(count as usize);
+    for index in 0..count {
+        let device = nvml.device_by_index(index).ok()?;
+        let temp_c = device
+            .temperature(nvml_wrapper::enum_wrappers::device::TemperatureSensor::Gpu)
+            .ok()
+            .map(|t| t as f32);
+        let utilization_percent = device.utilization_rates().ok().map(|u| u.gpu as f32);
+
+        gpus.push(GpuTelemetry {
+            index,
+            temp_c,
+            utilization_percent,
+        });
+    }
+
+    Some(gpus)
+}
+
+fn read_gpu_telemetry_nvidia_smi() -> Vec<GpuTelemetry> {
+    let output = match Command::new("nvidia-smi")
+        .args([
+            "--query-gpu=temperature.gpu,utilization.gpu",
+            "--format=csv,noheader,nounits",
+        ])
+        .output()
+    {
+        Ok(output) if output.status.success() => output,
+        _ => return Vec::new(),
+    };
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+            GpuTelemetry {
+                index: index as u32,
+                temp_c: fields.first().and_then(|v| v.parse().ok()),
+                utilization_percent: fields.get(1).and_then(|v| v.parse().ok()),
+            }
+        })
+        .collect()
+}
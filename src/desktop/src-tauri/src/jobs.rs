@@ -0,0 +1,286 @@
+// ============ Job / worker manager ============
+//
+// `start_node`/`stop_node` used to track a single opaque `Child` with no
+// visibility into the concurrent work it does (the settings already
+// expose `max_concurrent_jobs`, but nothing used it). This module gives
+// operators the same kind of worker introspection and control a
+// server-side task manager would: a registry of in-flight jobs, each
+// individually pausable/resumable/cancellable, with enough metadata
+// persisted to disk that the registry survives an app restart.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc;
+
+// A worker alternates between an active burst and an idle gap so the
+// `Idle` state is actually observable, rather than sitting in `Active`
+// forever. Either phase can be interrupted by a control message at any
+// time.
+const WORK_BURST: Duration = Duration::from_secs(20);
+const IDLE_GAP: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    Active,
+    Idle,
+    Paused,
+    Dead,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRecord {
+    pub id: String,
+    pub kind: String,
+    pub state: JobState,
+    pub started_at: u64,
+    pub last_error: Option<String>,
+}
+
+enum JobControlMessage {
+    Pause,
+    Resume,
+    Cancel,
+}
+
+struct JobEntry {
+    record: Arc<Mutex<JobRecord>>,
+    control_tx: mpsc::UnboundedSender<JobControlMessage>,
+}
+
+pub struct JobManager {
+    jobs: Mutex<HashMap<String, JobEntry>>,
+    next_id: AtomicU64,
+}
+
+impl JobManager {
+    pub fn new() -> Self {
+        Self {
+            jobs: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Spawns a worker task for a new job of the given `kind` and adds it
+    /// to the registry. Returns the job's id.
+    pub fn spawn_job(&self, kind: String) -> String {
+        let id = format!("{kind}-{}", self.next_id.fetch_add(1, Ordering::Relaxed));
+        let (control_tx, control_rx) = mpsc::unbounded_channel();
+
+        let record = Arc::new(Mutex::new(JobRecord {
+            id: id.clone(),
+            kind,
+            state: JobState::Active,
+            started_at: now_unix(),
+            last_error: None,
+        }));
+
+        let mut jobs = self.jobs.lock().unwrap();
+        jobs.insert(
+            id.clone(),
+            JobEntry {
+                record: record.clone(),
+                control_tx,
+            },
+        );
+        drop(jobs);
+
+        run_worker(record, control_rx);
+        id
+    }
+
+    pub fn list(&self) -> Vec<JobRecord> {
+        self.jobs
+            .lock()
+            .unwrap()
+            .values()
+            .map(|entry| entry.record.lock().unwrap().clone())
+            .collect()
+    }
+
+    /// Drops `Dead` jobs from the registry. Every `start_node` spawns
+    /// `max_concurrent_jobs` fresh entries and `stop_node` only cancels
+    /// them (marks them `Dead`), so without this an always-on node would
+    /// leak a handful of never-reclaimed map entries, and `jobs.json` rows,
+    /// on every start/stop cycle for the life of the app.
+    pub fn evict_dead(&self) {
+        self.jobs
+            .lock()
+            .unwrap()
+            .retain(|_, entry| entry.record.lock().unwrap().state != JobState::Dead);
+    }
+
+    fn send_control(&self, id: &str, message: JobControlMessage) -> Result<(), String> {
+        let jobs = self.jobs.lock().unwrap();
+        let entry = jobs.get(id).ok_or_else(|| format!("No such job: {id}"))?;
+        entry
+            .control_tx
+            .send(message)
+            .map_err(|_| format!("Job {id} is no longer accepting control messages"))
+    }
+
+    /// Restores job metadata persisted from a previous run. Restored jobs
+    /// cannot have live workers (their process state didn't survive the
+    /// restart), so they're recorded as dead with an explanatory error.
+    pub fn restore(&self, records: Vec<JobRecord>) {
+        let mut jobs = self.jobs.lock().unwrap();
+        for mut record in records {
+            bump_next_id(&self.next_id, &record.id);
+
+            if record.state != JobState::Dead {
+                record.state = JobState::Dead;
+                record.last_error = Some("Job did not survive an app restart".to_string());
+            }
+            let (control_tx, _control_rx) = mpsc::unbounded_channel();
+            jobs.insert(
+                record.id.clone(),
+                JobEntry {
+                    record: Arc::new(Mutex::new(record)),
+                    control_tx,
+                },
+            );
+        }
+    }
+}
+
+/// Advances `next_id` past whatever numeric suffix `id` ends in, so newly
+/// spawned jobs in this process can't collide with ids restored from a
+/// previous run's `jobs.json` (`next_id` otherwise always restarts at 1).
+fn bump_next_id(next_id: &AtomicU64, id: &str) {
+    if let Some(n) = id.rsplit('-').next().and_then(|s| s.parse::<u64>().ok()) {
+        next_id.fetch_max(n + 1, Ordering::Relaxed);
+    }
+}
+
+// Real job execution is driven by the node-agent; this task stands in for
+// it by alternating between an active burst and an idle gap so the
+// pause/resume/cancel control surface the manager exposes actually has
+// observable states to control, rather than sitting in `Active` forever.
+fn run_worker(record: Arc<Mutex<JobRecord>>, mut control_rx: mpsc::UnboundedReceiver<JobControlMessage>) {
+    tauri::async_runtime::spawn(async move {
+        'outer: loop {
+            set_state(&record, JobState::Active);
+            tokio::select! {
+                _ = tokio::time::sleep(WORK_BURST) => {}
+                message = control_rx.recv() => match message {
+                    Some(JobControlMessage::Cancel) | None => break 'outer,
+                    Some(JobControlMessage::Pause) => {
+                        if !wait_for_resume(&record, &mut control_rx).await {
+                            break 'outer;
+                        }
+                        continue 'outer;
+                    }
+                    Some(JobControlMessage::Resume) => continue 'outer,
+                },
+            }
+
+            set_state(&record, JobState::Idle);
+            tokio::select! {
+                _ = tokio::time::sleep(IDLE_GAP) => {}
+                message = control_rx.recv() => match message {
+                    Some(JobControlMessage::Cancel) | None => break 'outer,
+                    Some(JobControlMessage::Pause) => {
+                        if !wait_for_resume(&record, &mut control_rx).await {
+                            break 'outer;
+                        }
+                    }
+                    Some(JobControlMessage::Resume) => {}
+                },
+            }
+        }
+
+        set_state(&record, JobState::Dead);
+    });
+}
+
+/// Blocks the worker until a `Resume` arrives, discarding any redundant
+/// `Pause` messages received in the meantime. Returns `false` if the job
+/// was cancelled (or the control channel closed) while paused.
+async fn wait_for_resume(
+    record: &Arc<Mutex<JobRecord>>,
+    control_rx: &mut mpsc::UnboundedReceiver<JobControlMessage>,
+) -> bool {
+    set_state(record, JobState::Paused);
+    loop {
+        match control_rx.recv().await {
+            Some(JobControlMessage::Resume) => return true,
+            Some(JobControlMessage::Pause) => continue,
+            Some(JobControlMessage::Cancel) | None => return false,
+        }
+    }
+}
+
+fn set_state(record: &Arc<Mutex<JobRecord>>, state: JobState) {
+    record.lock().unwrap().state = state;
+}
+
+fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn jobs_metadata_path() -> Option<std::path::PathBuf> {
+    let config_dir = directories::ProjectDirs::from("com", "rhizos", "cloud")?;
+    Some(config_dir.config_dir().join("jobs.json"))
+}
+
+pub fn load_job_metadata() -> Vec<JobRecord> {
+    let Some(path) = jobs_metadata_path() else {
+        return Vec::new();
+    };
+
+    std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn persist_jobs(manager: &JobManager) -> Result<(), String> {
+    let path = jobs_metadata_path().ok_or("Could not determine config directory")?;
+    std::fs::create_dir_all(path.parent().unwrap())
+        .map_err(|e| format!("Failed to create config directory: {}", e))?;
+
+    let json = serde_json::to_string_pretty(&manager.list())
+        .map_err(|e| format!("Failed to serialize job metadata: {}", e))?;
+
+    std::fs::write(&path, json).map_err(|e| format!("Failed to write job metadata: {}", e))
+}
+
+// ============ Commands ============
+
+#[tauri::command]
+pub fn list_jobs(jobs: tauri::State<JobManager>) -> Vec<JobRecord> {
+    jobs.list()
+}
+
+#[tauri::command]
+pub fn pause_job(id: String, jobs: tauri::State<JobManager>) -> Result<(), String> {
+    jobs.send_control(&id, JobControlMessage::Pause)?;
+    update_state(&jobs, &id, JobState::Paused);
+    persist_jobs(&jobs)
+}
+
+#[tauri::command]
+pub fn resume_job(id: String, jobs: tauri::State<JobManager>) -> Result<(), String> {
+    jobs.send_control(&id, JobControlMessage::Resume)?;
+    update_state(&jobs, &id, JobState::Active);
+    persist_jobs(&jobs)
+}
+
+#[tauri::command]
+pub fn cancel_job(id: String, jobs: tauri::State<JobManager>) -> Result<(), String> {
+    jobs.send_control(&id, JobControlMessage::Cancel)?;
+    update_state(&jobs, &id, JobState::Dead);
+    persist_jobs(&jobs)
+}
+
+fn update_state(jobs: &JobManager, id: &str, state: JobState) {
+    if let Some(entry) = jobs.jobs.lock().unwrap().get(id) {
+        entry.record.lock().unwrap().state = state;
+    }
+}
@@ -1,36 +1,46 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod autostart;
+mod earnings;
+mod hardware;
+mod jobs;
+mod node;
+mod supervisor;
+mod updater;
+
+use autostart::apply_startup_settings;
+use earnings::estimate_earnings;
+use hardware::{get_hardware_info, spawn_telemetry_loop};
+use jobs::{cancel_job, list_jobs, pause_job, resume_job, JobManager};
+use node::{get_node_status, start_node, stop_node, NodeProcess};
+use supervisor::{get_recent_logs, LogBuffer};
+use updater::check_for_node_update;
 use serde::{Deserialize, Serialize};
-use std::process::{Child, Command};
-use std::sync::Mutex;
 use tauri::{
     menu::{Menu, MenuItem},
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
-    Manager, State,
+    Manager,
 };
 
-// ============ State ============
-
-struct NodeProcess(Mutex<Option<Child>>);
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct NodeSettings {
+pub(crate) struct NodeSettings {
     orchestrator_url: String,
-    auto_start: bool,
-    start_minimized: bool,
+    pub(crate) auto_start: bool,
+    pub(crate) start_minimized: bool,
+    pub(crate) auto_restart: bool,
     wallet_address: String,
-    max_concurrent_jobs: u32,
+    pub(crate) max_concurrent_jobs: u32,
     max_memory_percent: u32,
-    pricing: PricingConfig,
+    pub(crate) pricing: PricingConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct PricingConfig {
-    gpu_hour_cents: u32,
-    cpu_core_hour_cents: u32,
-    memory_gb_hour_cents: u32,
-    minimum_cents: u32,
+pub(crate) struct PricingConfig {
+    pub(crate) gpu_hour_cents: u32,
+    pub(crate) cpu_core_hour_cents: u32,
+    pub(crate) memory_gb_hour_cents: u32,
+    pub(crate) minimum_cents: u32,
 }
 
 impl Default for NodeSettings {
@@ -39,6 +49,7 @@ impl Default for NodeSettings {
             orchestrator_url: "http://localhost:8080".to_string(),
             auto_start: false,
             start_minimized: false,
+            auto_restart: false,
             wallet_address: String::new(),
             max_concurrent_jobs: 4,
             max_memory_percent: 80,
@@ -52,147 +63,8 @@ impl Default for NodeSettings {
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-struct NodeStatus {
-    running: bool,
-    pid: Option<u32>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-struct HardwareInfo {
-    cpu: CpuInfo,
-    memory: MemoryInfo,
-    gpus: Vec<GpuInfo>,
-    storage: StorageInfo,
-    docker_version: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-struct CpuInfo {
-    model: String,
-    cores: u32,
-    threads: u32,
-}
-
-#[derive(Debug, Clone, Serialize)]
-struct MemoryInfo {
-    total_mb: u64,
-    available_mb: u64,
-}
-
-#[derive(Debug, Clone, Serialize)]
-struct GpuInfo {
-    model: String,
-    vram_mb: u64,
-    driver_version: String,
-}
-
-#[derive(Debug, Clone, Serialize)]
-struct StorageInfo {
-    total_gb: u64,
-    available_gb: u64,
-}
-
 // ============ Commands ============
 
-#[tauri::command]
-fn get_node_status(node: State<NodeProcess>) -> NodeStatus {
-    let guard = node.0.lock().unwrap();
-    match &*guard {
-        Some(child) => NodeStatus {
-            running: true,
-            pid: Some(child.id()),
-        },
-        None => NodeStatus {
-            running: false,
-            pid: None,
-        },
-    }
-}
-
-#[tauri::command]
-async fn start_node(
-    node: State<'_, NodeProcess>,
-    orchestrator_url: String,
-) -> Result<NodeStatus, String> {
-    let mut guard = node.0.lock().unwrap();
-
-    if guard.is_some() {
-        return Err("Node is already running".to_string());
-    }
-
-    // Find the node-agent binary
-    let node_binary = if cfg!(target_os = "windows") {
-        "rhizos-node.exe"
-    } else {
-        "rhizos-node"
-    };
-
-    // Try to find the binary in various locations
-    let binary_path = std::env::current_exe()
-        .ok()
-        .and_then(|p| p.parent().map(|p| p.join(node_binary)))
-        .filter(|p| p.exists())
-        .or_else(|| {
-            // Development path
-            Some(std::path::PathBuf::from("../node-agent/target/release").join(node_binary))
-        });
-
-    let path = binary_path.ok_or("Could not find node-agent binary")?;
-
-    let child = Command::new(&path)
-        .args(["start", "--orchestrator", &orchestrator_url])
-        .spawn()
-        .map_err(|e| format!("Failed to start node: {}", e))?;
-
-    let pid = child.id();
-    *guard = Some(child);
-
-    Ok(NodeStatus {
-        running: true,
-        pid: Some(pid),
-    })
-}
-
-#[tauri::command]
-async fn stop_node(node: State<'_, NodeProcess>) -> Result<NodeStatus, String> {
-    let mut guard = node.0.lock().unwrap();
-
-    if let Some(mut child) = guard.take() {
-        child.kill().map_err(|e| format!("Failed to stop node: {}", e))?;
-        child.wait().ok();
-    }
-
-    Ok(NodeStatus {
-        running: false,
-        pid: None,
-    })
-}
-
-#[tauri::command]
-async fn get_hardware_info() -> Result<HardwareInfo, String> {
-    // Run the node-agent info command and parse output
-    // For now, return mock data - real implementation would call the binary
-
-    Ok(HardwareInfo {
-        cpu: CpuInfo {
-            model: "Unknown".to_string(),
-            cores: num_cpus::get_physical() as u32,
-            threads: num_cpus::get() as u32,
-        },
-        memory: MemoryInfo {
-            total_mb: 0,
-            available_mb: 0,
-        },
-        gpus: vec![],
-        storage: StorageInfo {
-            total_gb: 0,
-            available_gb: 0,
-        },
-        docker_version: None,
-    })
-}
-
 #[tauri::command]
 fn get_settings() -> NodeSettings {
     // Load from config file
@@ -214,10 +86,12 @@ fn save_settings(settings: NodeSettings) -> Result<(), String> {
     std::fs::write(&config_path, json)
         .map_err(|e| format!("Failed to write settings: {}", e))?;
 
+    autostart::apply_auto_start(settings.auto_start)?;
+
     Ok(())
 }
 
-fn load_settings() -> Option<NodeSettings> {
+pub(crate) fn load_settings() -> Option<NodeSettings> {
     let config_dir = directories::ProjectDirs::from("com", "rhizos", "cloud")?;
     let config_path = config_dir.config_dir().join("settings.json");
 
@@ -230,8 +104,12 @@ fn load_settings() -> Option<NodeSettings> {
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
-        .manage(NodeProcess(Mutex::new(None)))
+        .manage(NodeProcess::new())
+        .manage(JobManager::new())
+        .manage(LogBuffer::new())
         .setup(|app| {
+            app.state::<JobManager>().restore(jobs::load_job_metadata());
+
             // Create system tray
             let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
             let show = MenuItem::with_id(app, "show", "Show Window", true, None::<&str>)?;
@@ -271,6 +149,9 @@ fn main() {
                 })
                 .build(app)?;
 
+            spawn_telemetry_loop(app.handle().clone());
+            autostart::sync_startup_settings(app.handle(), &load_settings().unwrap_or_default())?;
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -280,6 +161,14 @@ fn main() {
             get_hardware_info,
             get_settings,
             save_settings,
+            list_jobs,
+            pause_job,
+            resume_job,
+            cancel_job,
+            check_for_node_update,
+            apply_startup_settings,
+            get_recent_logs,
+            estimate_earnings,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,159 @@
+// ============ Node process lifecycle ============
+//
+// Owns the `rhizos-node` child process: resolving its binary, starting
+// and stopping it, and reporting whether it's actually alive. Crash
+// detection, auto-restart, and log capture live in `supervisor`, which
+// watches whatever child is stored here.
+
+use crate::jobs::{self, JobManager};
+use crate::supervisor;
+use crate::updater;
+use crate::load_settings;
+use serde::Serialize;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, State};
+use tokio::process::{Child, Command};
+
+pub struct NodeProcess {
+    pub(crate) child: Mutex<Option<Child>>,
+    /// Bumped on every `start_node`/`stop_node`. A supervisor task captures
+    /// the generation it was spawned for and treats a mismatch as "this
+    /// node lifecycle is no longer mine" — the only reliable way to tell
+    /// "stop_node cleared the slot" apart from "a restart attempt is just
+    /// between `try_wait` polls", and to keep a restart that wins a race
+    /// against a concurrent `stop_node` from resurrecting the process.
+    pub(crate) generation: AtomicU64,
+}
+
+impl NodeProcess {
+    pub fn new() -> Self {
+        Self {
+            child: Mutex::new(None),
+            generation: AtomicU64::new(0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeStatus {
+    pub running: bool,
+    pub pid: Option<u32>,
+}
+
+#[tauri::command]
+pub fn get_node_status(node: State<NodeProcess>) -> NodeStatus {
+    let mut guard = node.child.lock().unwrap();
+
+    // A `Child` handle existing doesn't mean the process is still alive;
+    // `try_wait` is the only way to know without blocking.
+    let running = matches!(guard.as_mut().map(|c| c.try_wait()), Some(Ok(None)));
+    if !running {
+        *guard = None;
+    }
+
+    NodeStatus {
+        running,
+        pid: guard.as_ref().and_then(|c| c.id()),
+    }
+}
+
+#[tauri::command]
+pub async fn start_node(
+    app: AppHandle,
+    node: State<'_, NodeProcess>,
+    jobs: State<'_, JobManager>,
+    orchestrator_url: String,
+) -> Result<NodeStatus, String> {
+    {
+        let guard = node.child.lock().unwrap();
+        if guard.is_some() {
+            return Err("Node is already running".to_string());
+        }
+    }
+
+    let child = launch_child(&app, &orchestrator_url).await?;
+    let pid = child.id();
+    *node.child.lock().unwrap() = Some(child);
+
+    let generation = node.generation.fetch_add(1, Ordering::SeqCst) + 1;
+    supervisor::spawn_supervisor(app.clone(), orchestrator_url, generation);
+
+    let max_concurrent_jobs = load_settings().unwrap_or_default().max_concurrent_jobs;
+    for _ in 0..max_concurrent_jobs {
+        jobs.spawn_job("node-job".to_string());
+    }
+    jobs::persist_jobs(&jobs)?;
+
+    Ok(NodeStatus {
+        running: true,
+        pid,
+    })
+}
+
+#[tauri::command]
+pub async fn stop_node(
+    node: State<'_, NodeProcess>,
+    jobs: State<'_, JobManager>,
+) -> Result<NodeStatus, String> {
+    // Bump the generation *before* touching the child so a supervisor task
+    // that's mid-backoff or mid-relaunch for the outgoing instance sees the
+    // mismatch and backs off instead of replacing the slot we're about to
+    // clear (or one we just cleared) with a freshly relaunched process.
+    node.generation.fetch_add(1, Ordering::SeqCst);
+    let child = node.child.lock().unwrap().take();
+
+    if let Some(mut child) = child {
+        child
+            .start_kill()
+            .map_err(|e| format!("Failed to stop node: {}", e))?;
+        child.wait().await.ok();
+    }
+
+    let mut cancel_errors = Vec::new();
+    for job in jobs.list() {
+        if job.state != jobs::JobState::Dead {
+            if let Err(e) = jobs::cancel_job(job.id.clone(), jobs.clone()) {
+                cancel_errors.push(format!("{}: {}", job.id, e));
+            }
+        }
+    }
+
+    // The jobs from this run are all cancelled (or already dead) now, so
+    // there's nothing left worth keeping them in the registry for; drop
+    // them before persisting so an always-on node doesn't leak a handful
+    // of entries into memory and jobs.json on every start/stop cycle.
+    jobs.evict_dead();
+    jobs::persist_jobs(&jobs)?;
+
+    if !cancel_errors.is_empty() {
+        return Err(format!(
+            "Node stopped, but failed to cancel job(s): {}",
+            cancel_errors.join(", ")
+        ));
+    }
+
+    Ok(NodeStatus {
+        running: false,
+        pid: None,
+    })
+}
+
+/// Spawns a fresh `rhizos-node` child with piped stdout/stderr and hands
+/// the pipes off to the supervisor's log streamer. Used both by
+/// `start_node` and by the supervisor when restarting a crashed node.
+pub(crate) async fn launch_child(app: &AppHandle, orchestrator_url: &str) -> Result<Child, String> {
+    let path = updater::resolve_node_binary(app).await?;
+
+    let mut child = Command::new(&path)
+        .args(["start", "--orchestrator", orchestrator_url])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to start node: {}", e))?;
+
+    supervisor::stream_child_output(app.clone(), child.stdout.take(), child.stderr.take());
+
+    Ok(child)
+}
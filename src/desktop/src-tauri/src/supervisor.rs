@@ -0,0 +1,171 @@
+// ============ Process supervision ============
+//
+// The spawned child used to be fire-and-forget: if `rhizos-node` crashed,
+// `get_node_status` kept reporting `running: true` because it only
+// checked whether a `Child` handle existed, never whether the PID was
+// still alive. This module periodically polls the child, tells the
+// frontend when it exits, and (when `auto_restart` is enabled) relaunches
+// it with exponential backoff. It also captures the child's stdout/stderr
+// into a bounded ring buffer the frontend can fetch whole through
+// `get_recent_logs`.
+
+use crate::load_settings;
+use crate::node::{launch_child, NodeProcess};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+const LOG_BUFFER_CAPACITY: usize = 1000;
+const NODE_EXITED_EVENT: &str = "node-exited";
+const NODE_LOG_EVENT: &str = "node-log";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogLine {
+    pub stream: &'static str,
+    pub line: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct NodeExited {
+    code: Option<i32>,
+    will_restart: bool,
+}
+
+pub struct LogBuffer(Mutex<VecDeque<LogLine>>);
+
+impl LogBuffer {
+    pub fn new() -> Self {
+        Self(Mutex::new(VecDeque::with_capacity(LOG_BUFFER_CAPACITY)))
+    }
+
+    fn push(&self, line: LogLine) {
+        let mut buf = self.0.lock().unwrap();
+        if buf.len() == LOG_BUFFER_CAPACITY {
+            buf.pop_front();
+        }
+        buf.push_back(line);
+    }
+}
+
+#[tauri::command]
+pub fn get_recent_logs(logs: State<LogBuffer>) -> Vec<LogLine> {
+    logs.0.lock().unwrap().iter().cloned().collect()
+}
+
+/// Streams the child's stdout/stderr line-by-line into the ring buffer
+/// and out to the frontend as `node-log` events.
+pub fn stream_child_output(
+    app: AppHandle,
+    stdout: Option<impl AsyncRead + Unpin + Send + 'static>,
+    stderr: Option<impl AsyncRead + Unpin + Send + 'static>,
+) {
+    if let Some(stdout) = stdout {
+        spawn_line_reader(app.clone(), stdout, "stdout");
+    }
+    if let Some(stderr) = stderr {
+        spawn_line_reader(app, stderr, "stderr");
+    }
+}
+
+fn spawn_line_reader(
+    app: AppHandle,
+    reader: impl AsyncRead + Unpin + Send + 'static,
+    stream: &'static str,
+) {
+    tauri::async_runtime::spawn(async move {
+        let mut lines = BufReader::new(reader).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            let entry = LogLine { stream, line };
+            app.state::<LogBuffer>().push(entry.clone());
+            let _ = app.emit(NODE_LOG_EVENT, &entry);
+        }
+    });
+}
+
+/// Watches the node child for exit and, when `auto_restart` is enabled in
+/// settings, relaunches it with exponential backoff. One instance runs per
+/// `start_node` call, tagged with the generation `start_node` handed it;
+/// it exits as soon as that generation is superseded by a later
+/// `start_node`/`stop_node` rather than inferring that from the child
+/// slot being empty, which a failed or in-flight relaunch can also cause.
+pub fn spawn_supervisor(app: AppHandle, orchestrator_url: String, generation: u64) {
+    tauri::async_runtime::spawn(async move {
+        let mut backoff = Duration::from_secs(1);
+
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            let node = app.state::<NodeProcess>();
+            if node.generation.load(Ordering::SeqCst) != generation {
+                break; // superseded by a later start_node/stop_node
+            }
+
+            let exit_status = {
+                let mut guard = node.child.lock().unwrap();
+                match guard.as_mut() {
+                    Some(child) => match child.try_wait() {
+                        Ok(Some(status)) => {
+                            *guard = None;
+                            Some(status.code())
+                        }
+                        _ => None,
+                    },
+                    // Empty while this generation still owns the slot: the
+                    // last relaunch failed, or `get_node_status` reaped the
+                    // exited child before this poll did.
+                    None if node.generation.load(Ordering::SeqCst) == generation => Some(None),
+                    None => None,
+                }
+            };
+
+            let Some(code) = exit_status else {
+                continue;
+            };
+
+            let auto_restart = load_settings().unwrap_or_default().auto_restart;
+            let _ = app.emit(
+                NODE_EXITED_EVENT,
+                &NodeExited {
+                    code,
+                    will_restart: auto_restart,
+                },
+            );
+
+            if !auto_restart {
+                break;
+            }
+
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+
+            if app.state::<NodeProcess>().generation.load(Ordering::SeqCst) != generation {
+                break; // stop_node fired during the backoff; don't relaunch
+            }
+
+            match launch_child(&app, &orchestrator_url).await {
+                Ok(mut child) => {
+                    let node = app.state::<NodeProcess>();
+                    if node.generation.load(Ordering::SeqCst) != generation {
+                        // stop_node raced us while launch_child was in
+                        // flight — the user asked for the node to stop, so
+                        // don't resurrect it.
+                        let _ = child.start_kill();
+                        break;
+                    }
+                    node.child.lock().unwrap().replace(child);
+                }
+                Err(_) => {
+                    // Keep looping — the next iteration retries after
+                    // another backoff, as long as this generation still
+                    // owns the supervision slot.
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,277 @@
+// ============ Node-agent binary resolver / self-updater ============
+//
+// `start_node` used to only look for `rhizos-node`/`rhizos-node.exe` next
+// to the app binary or in a fixed dev-tree path, and fail hard if neither
+// existed. This module resolves (and if necessary fetches) the binary
+// from GitHub releases instead, so nodes don't need a manual install step
+// and can be kept current automatically.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+
+const RELEASES_REPO: &str = "rhizos-io/rhizos-node";
+const SETUP_STATUS_EVENT: &str = "setup-status";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SetupStatus {
+    pub phase: String,
+    pub percent: Option<u8>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateStatus {
+    pub current_version: Option<String>,
+    pub latest_version: String,
+    pub update_available: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    assets: Vec<GithubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+fn node_binary_name() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "rhizos-node.exe"
+    } else {
+        "rhizos-node"
+    }
+}
+
+fn install_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    use tauri::Manager;
+
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Could not determine app data directory: {}", e))?
+        .join("node-agent");
+
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create node-agent directory: {}", e))?;
+
+    Ok(dir)
+}
+
+fn version_file_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(install_dir(app)?.join("VERSION"))
+}
+
+fn installed_version(app: &AppHandle) -> Option<String> {
+    let path = version_file_path(app).ok()?;
+    std::fs::read_to_string(path).ok().map(|v| v.trim().to_string())
+}
+
+/// Locates a usable `rhizos-node` binary, downloading and installing the
+/// latest release if one isn't already present next to the app, in the
+/// dev-tree build path, or in our managed install directory.
+pub async fn resolve_node_binary(app: &AppHandle) -> Result<PathBuf, String> {
+    let node_binary = node_binary_name();
+
+    let candidate = std::env::current_exe()
+        .ok()
+        .and_then(|p| p.parent().map(|p| p.join(node_binary)))
+        .filter(|p| p.exists())
+        .or_else(|| {
+            let dev_path = std::path::PathBuf::from("../node-agent/target/release").join(node_binary);
+            dev_path.exists().then_some(dev_path)
+        });
+
+    if let Some(path) = candidate {
+        return Ok(path);
+    }
+
+    let managed_path = install_dir(app)?.join(node_binary);
+    if managed_path.exists() {
+        return Ok(managed_path);
+    }
+
+    download_latest_release(app).await
+}
+
+#[tauri::command]
+pub async fn check_for_node_update(app: AppHandle) -> Result<UpdateStatus, String> {
+    let release = fetch_latest_release().await?;
+    let current_version = installed_version(&app);
+    let update_available = current_version.as_deref() != Some(release.tag_name.as_str());
+
+    if update_available {
+        download_latest_release(&app).await?;
+    }
+
+    Ok(UpdateStatus {
+        current_version,
+        latest_version: release.tag_name,
+        update_available,
+    })
+}
+
+async fn fetch_latest_release() -> Result<GithubRelease, String> {
+    let url = format!("https://api.github.com/repos/{RELEASES_REPO}/releases/latest");
+
+    let response = reqwest::Client::new()
+        .get(&url)
+        .header("User-Agent", "rhizos-desktop")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach GitHub releases: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("GitHub releases request failed: {}", response.status()));
+    }
+
+    response
+        .json::<GithubRelease>()
+        .await
+        .map_err(|e| format!("Failed to parse release metadata: {}", e))
+}
+
+fn asset_suffix() -> &'static str {
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("windows", "x86_64") => "x86_64-pc-windows-msvc.zip",
+        ("macos", "aarch64") => "aarch64-apple-darwin.tar.gz",
+        ("macos", _) => "x86_64-apple-darwin.tar.gz",
+        ("linux", "aarch64") => "aarch64-unknown-linux-gnu.tar.gz",
+        _ => "x86_64-unknown-linux-gnu.tar.gz",
+    }
+}
+
+async fn download_latest_release(app: &AppHandle) -> Result<PathBuf, String> {
+    emit_status(app, "checking", None);
+    let release = fetch_latest_release().await?;
+
+    let suffix = asset_suffix();
+    let asset = release
+        .assets
+        .iter()
+        .find(|a| a.name.ends_with(suffix))
+        .ok_or_else(|| format!("No release asset found for {suffix}"))?;
+
+    let checksum_asset = release
+        .assets
+        .iter()
+        .find(|a| a.name == format!("{}.sha256", asset.name))
+        .ok_or_else(|| format!("No checksum published for {}", asset.name))?;
+
+    emit_status(app, "downloading", Some(0));
+    let archive_bytes = download_with_progress(app, &asset.browser_download_url).await?;
+
+    emit_status(app, "verifying", None);
+    verify_checksum(&archive_bytes, &checksum_asset.browser_download_url).await?;
+
+    emit_status(app, "extracting", None);
+    let dest = install_dir(app)?;
+    extract_archive(&asset.name, &archive_bytes, &dest)?;
+
+    let binary_path = dest.join(node_binary_name());
+    mark_executable(&binary_path)?;
+
+    std::fs::write(version_file_path(app)?, &release.tag_name)
+        .map_err(|e| format!("Failed to record installed version: {}", e))?;
+
+    emit_status(app, "complete", Some(100));
+    Ok(binary_path)
+}
+
+async fn download_with_progress(app: &AppHandle, url: &str) -> Result<Vec<u8>, String> {
+    use futures_util::StreamExt;
+
+    let response = reqwest::get(url)
+        .await
+        .map_err(|e| format!("Failed to download node-agent: {}", e))?;
+
+    let total_size = response.content_length();
+    let mut downloaded: u64 = 0;
+    let mut bytes = Vec::new();
+    let mut stream = response.bytes_stream();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("Download interrupted: {}", e))?;
+        downloaded += chunk.len() as u64;
+        bytes.extend_from_slice(&chunk);
+
+        let percent = total_size.map(|total| ((downloaded * 100) / total.max(1)) as u8);
+        emit_status(app, "downloading", percent);
+    }
+
+    Ok(bytes)
+}
+
+async fn verify_checksum(bytes: &[u8], checksum_url: &str) -> Result<(), String> {
+    use sha2::{Digest, Sha256};
+
+    let expected = reqwest::get(checksum_url)
+        .await
+        .map_err(|e| format!("Failed to download checksum: {}", e))?
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read checksum: {}", e))?;
+    let expected = expected.split_whitespace().next().unwrap_or("").to_lowercase();
+
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    let actual = format!("{:x}", hasher.finalize());
+
+    if actual != expected {
+        return Err(format!(
+            "Checksum mismatch for downloaded node-agent (expected {expected}, got {actual})"
+        ));
+    }
+
+    Ok(())
+}
+
+fn extract_archive(asset_name: &str, bytes: &[u8], dest: &Path) -> Result<(), String> {
+    if asset_name.ends_with(".zip") {
+        let cursor = std::io::Cursor::new(bytes);
+        let mut archive =
+            zip::ZipArchive::new(cursor).map_err(|e| format!("Failed to read archive: {}", e))?;
+        archive
+            .extract(dest)
+            .map_err(|e| format!("Failed to extract archive: {}", e))?;
+    } else {
+        let decoder = flate2::read::GzDecoder::new(bytes);
+        let mut archive = tar::Archive::new(decoder);
+        archive
+            .unpack(dest)
+            .map_err(|e| format!("Failed to extract archive: {}", e))?;
+    }
+
+    Ok(())
+}
+
+fn mark_executable(path: &Path) -> Result<(), String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(path)
+            .map_err(|e| format!("Failed to read binary permissions: {}", e))?
+            .permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(path, perms)
+            .map_err(|e| format!("Failed to mark binary executable: {}", e))?;
+    }
+
+    #[cfg(not(unix))]
+    let _ = path;
+
+    Ok(())
+}
+
+fn emit_status(app: &AppHandle, phase: &str, percent: Option<u8>) {
+    let _ = app.emit(
+        SETUP_STATUS_EVENT,
+        &SetupStatus {
+            phase: phase.to_string(),
+            percent,
+        },
+    );
+}